@@ -0,0 +1,83 @@
+use crate::source::Source;
+use parquet::basic::Type as PhysicalType;
+use parquet::file::properties::ReaderProperties;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::serialized_reader::ReadOptionsBuilder;
+use parquet::schema::types::SchemaDescriptor;
+use prettytable::{Cell, Row as PrettyTableRow, Table};
+use std::io::{Error, ErrorKind, Result};
+
+pub fn probe(file: &str, anonymous: bool, column: &str, value: &str) -> Result<bool> {
+    let source = Source::open(file, anonymous)?;
+    let options = ReadOptionsBuilder::new()
+        .with_reader_properties(ReaderProperties::builder().set_read_bloom_filter(true).build())
+        .build();
+    let reader = SerializedFileReader::new_with_options(source, options)?;
+
+    let schema_descr = reader.metadata().file_metadata().schema_descr();
+    let column_idx = column_index(schema_descr, column)?;
+    let physical_type = schema_descr.column(column_idx).physical_type();
+
+    let mut table = Table::new();
+    table.add_row(PrettyTableRow::new(vec![
+        Cell::new("Row group"),
+        Cell::new("Result"),
+    ]));
+
+    let mut any_present = false;
+    for i in 0..reader.num_row_groups() {
+        let row_group_reader = reader.get_row_group(i)?;
+        let present = match row_group_reader.get_column_bloom_filter(column_idx) {
+            Some(bloom) => check_membership(bloom, physical_type, value)?,
+            // No bloom filter was written for this chunk; we can't rule it out.
+            None => true,
+        };
+        any_present |= present;
+        table.add_row(PrettyTableRow::new(vec![
+            Cell::new(&i.to_string()),
+            Cell::new(if present {
+                "may be present"
+            } else {
+                "definitely absent"
+            }),
+        ]));
+    }
+
+    table.printstd();
+    Ok(any_present)
+}
+
+fn column_index(schema_descr: &SchemaDescriptor, column: &str) -> Result<usize> {
+    schema_descr
+        .columns()
+        .iter()
+        .position(|col| col.name() == column)
+        .ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, format!("unknown column: {}", column))
+        })
+}
+
+fn check_membership(
+    bloom: &parquet::bloom_filter::Sbbf,
+    physical_type: PhysicalType,
+    value: &str,
+) -> Result<bool> {
+    match physical_type {
+        PhysicalType::INT32 => Ok(bloom.check(&parse_value::<i32>(value)?)),
+        PhysicalType::INT64 => Ok(bloom.check(&parse_value::<i64>(value)?)),
+        PhysicalType::FLOAT => Ok(bloom.check(&parse_value::<f32>(value)?)),
+        PhysicalType::DOUBLE => Ok(bloom.check(&parse_value::<f64>(value)?)),
+        PhysicalType::BOOLEAN => Ok(bloom.check(&parse_value::<bool>(value)?)),
+        PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY => Ok(bloom.check(&value)),
+        other => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("bloom probe isn't supported for column type {:?}", other),
+        )),
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| Error::new(ErrorKind::InvalidInput, format!("cannot parse `{}`", value)))
+}
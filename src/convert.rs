@@ -0,0 +1,203 @@
+use arrow::csv::ReaderBuilder as CsvReaderBuilder;
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::json::ReaderBuilder as JsonReaderBuilder;
+use arrow::record_batch::RecordBatchReader;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, Encoding};
+use parquet::file::properties::{EnabledStatistics, WriterProperties};
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind, Result};
+use std::sync::Arc;
+
+/// Writer knobs exposed as CLI flags, collected so `convert` doesn't take a
+/// long fixed argument list.
+pub struct ConvertOptions<'a> {
+    pub format: &'a str,
+    pub compression: &'a str,
+    pub encoding: Option<&'a str>,
+    pub row_group_size: Option<usize>,
+    pub statistics: &'a str,
+    pub schema_path: Option<&'a str>,
+}
+
+pub fn convert(input: &str, output: &str, options: ConvertOptions) -> Result<()> {
+    let schema = match options.schema_path {
+        Some(path) => read_schema(path)?,
+        None => infer_schema(input, options.format)?,
+    };
+
+    let props = build_writer_properties(&options)?;
+    let out_file = File::create(output)?;
+    let mut writer = ArrowWriter::try_new(out_file, schema.clone(), Some(props))
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+
+    let file = File::open(input)?;
+    match options.format {
+        "csv" => {
+            let reader = CsvReaderBuilder::new(schema)
+                .with_header(true)
+                .build(file)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+            write_batches(&mut writer, reader)?;
+        }
+        "json" => {
+            let reader = JsonReaderBuilder::new(schema)
+                .build(BufReader::new(file))
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+            write_batches(&mut writer, reader)?;
+        }
+        _ => unreachable!("Handled by value_parser"),
+    }
+
+    writer
+        .close()
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    Ok(())
+}
+
+fn write_batches<R: RecordBatchReader>(writer: &mut ArrowWriter<File>, reader: R) -> Result<()> {
+    for batch in reader {
+        let batch = batch.map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        writer
+            .write(&batch)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    }
+    Ok(())
+}
+
+fn read_schema(path: &str) -> Result<SchemaRef> {
+    let contents = std::fs::read_to_string(path)?;
+    let schema: Schema = serde_json::from_str(&contents)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    Ok(Arc::new(schema))
+}
+
+fn infer_schema(input: &str, format: &str) -> Result<SchemaRef> {
+    let file = File::open(input)?;
+    let schema = match format {
+        "csv" => {
+            let (schema, _) = arrow::csv::reader::Format::default()
+                .with_header(true)
+                .infer_schema(file, None)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+            schema
+        }
+        "json" => {
+            let mut reader = BufReader::new(file);
+            let (schema, _) = arrow::json::reader::infer_json_schema(&mut reader, None)
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+            schema
+        }
+        _ => unreachable!("Handled by value_parser"),
+    };
+    Ok(Arc::new(schema))
+}
+
+fn build_writer_properties(options: &ConvertOptions) -> Result<WriterProperties> {
+    let mut builder = WriterProperties::builder()
+        .set_compression(parse_compression(options.compression)?)
+        .set_statistics_enabled(parse_statistics(options.statistics)?);
+
+    if let Some(encoding) = options.encoding {
+        builder = match parse_encoding(encoding)? {
+            Some(encoding) => builder.set_dictionary_enabled(false).set_encoding(encoding),
+            None => builder.set_dictionary_enabled(true),
+        };
+    }
+    if let Some(row_group_size) = options.row_group_size {
+        builder = builder.set_max_row_group_size(row_group_size);
+    }
+
+    Ok(builder.build())
+}
+
+fn parse_compression(value: &str) -> Result<Compression> {
+    match value {
+        "uncompressed" => Ok(Compression::UNCOMPRESSED),
+        "snappy" => Ok(Compression::SNAPPY),
+        "gzip" => Ok(Compression::GZIP(Default::default())),
+        "zstd" => Ok(Compression::ZSTD(Default::default())),
+        "brotli" => Ok(Compression::BROTLI(Default::default())),
+        "lz4" => Ok(Compression::LZ4),
+        other => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unknown compression codec: {}", other),
+        )),
+    }
+}
+
+fn parse_statistics(value: &str) -> Result<EnabledStatistics> {
+    match value {
+        "none" => Ok(EnabledStatistics::None),
+        "chunk" => Ok(EnabledStatistics::Chunk),
+        "page" => Ok(EnabledStatistics::Page),
+        other => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unknown statistics level: {}", other),
+        )),
+    }
+}
+
+/// `None` means `"dictionary"`, which isn't a valid fallback encoding and is
+/// instead handled via `set_dictionary_enabled`.
+fn parse_encoding(value: &str) -> Result<Option<Encoding>> {
+    match value {
+        "plain" => Ok(Some(Encoding::PLAIN)),
+        "dictionary" => Ok(None),
+        "rle" => Ok(Some(Encoding::RLE)),
+        "delta_binary_packed" => Ok(Some(Encoding::DELTA_BINARY_PACKED)),
+        "delta_byte_array" => Ok(Some(Encoding::DELTA_BYTE_ARRAY)),
+        other => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unknown encoding: {}", other),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parquet::schema::types::ColumnPath;
+
+    fn options(encoding: Option<&str>) -> ConvertOptions<'_> {
+        ConvertOptions {
+            format: "csv",
+            compression: "snappy",
+            encoding,
+            row_group_size: None,
+            statistics: "page",
+            schema_path: None,
+        }
+    }
+
+    #[test]
+    fn dictionary_encoding_disables_fallback_encoding_instead_of_panicking() {
+        let props = build_writer_properties(&options(Some("dictionary"))).unwrap();
+        let col = ColumnPath::from("any_column");
+        assert!(props.dictionary_enabled(&col));
+        assert_eq!(props.encoding(&col), None);
+    }
+
+    #[test]
+    fn non_dictionary_encoding_disables_dictionary() {
+        let props = build_writer_properties(&options(Some("rle"))).unwrap();
+        let col = ColumnPath::from("any_column");
+        assert!(!props.dictionary_enabled(&col));
+        assert_eq!(props.encoding(&col), Some(Encoding::RLE));
+    }
+
+    #[test]
+    fn unknown_encoding_is_rejected() {
+        assert!(parse_encoding("bogus").is_err());
+    }
+
+    #[test]
+    fn unknown_compression_is_rejected() {
+        assert!(parse_compression("bogus").is_err());
+    }
+
+    #[test]
+    fn unknown_statistics_level_is_rejected() {
+        assert!(parse_statistics("bogus").is_err());
+    }
+}
@@ -0,0 +1,18 @@
+use crate::source::Source;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use std::io::Result;
+
+pub fn display(file: &str, anonymous: bool, row_groups: bool) -> Result<()> {
+    let source = Source::open(file, anonymous)?;
+    let reader = SerializedFileReader::new(source)?;
+    let metadata = reader.metadata();
+
+    if row_groups {
+        for (i, row_group) in metadata.row_groups().iter().enumerate() {
+            println!("{}\t{}", i, row_group.num_rows());
+        }
+    }
+
+    println!("{}", metadata.file_metadata().num_rows());
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::file::statistics::Statistics;
+use parquet::record::{Field, Row};
+use std::cmp::Ordering;
+use std::io::{Error, ErrorKind, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+impl Value {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(i) => Some(*i as f64),
+            Value::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    fn compare(&self, other: &Value) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.as_f64(), other.as_f64()) {
+            return a.partial_cmp(&b);
+        }
+        match (self, other) {
+            (Value::Str(a), Value::Str(b)) => a.partial_cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Predicate {
+    column: String,
+    op: Op,
+    value: Value,
+}
+
+const OPERATORS: [(&str, Op); 7] = [
+    ("!=", Op::Ne),
+    ("==", Op::Eq),
+    (">=", Op::Ge),
+    ("<=", Op::Le),
+    ("=", Op::Eq),
+    ("<", Op::Lt),
+    (">", Op::Gt),
+];
+
+impl Predicate {
+    /// Parse a predicate like `col >= 100` or `name == "foo"`.
+    pub fn parse(input: &str) -> Result<Predicate> {
+        for idx in input.char_indices().map(|(i, _)| i) {
+            let rest = &input[idx..];
+            let Some(&(token, op)) = OPERATORS.iter().find(|(token, _)| rest.starts_with(token))
+            else {
+                continue;
+            };
+            let column = input[..idx].trim().to_string();
+            let raw_value = input[idx + token.len()..].trim();
+            if column.is_empty() || raw_value.is_empty() {
+                break;
+            }
+            return Ok(Predicate {
+                column,
+                op,
+                value: parse_value(raw_value),
+            });
+        }
+        Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid filter expression: {}", input),
+        ))
+    }
+
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Whether the row group could possibly contain a matching row.
+    pub fn row_group_may_match(&self, row_group: &RowGroupMetaData) -> bool {
+        let Some(column) = row_group
+            .columns()
+            .iter()
+            .find(|col| col.column_path().string() == self.column)
+        else {
+            return true;
+        };
+        let Some(stats) = column.statistics() else {
+            return true;
+        };
+        let Some((min, max)) = stats_bounds(stats) else {
+            return true;
+        };
+
+        match self.op {
+            Op::Eq => !matches!(self.value.compare(&max), Some(Ordering::Greater))
+                && !matches!(self.value.compare(&min), Some(Ordering::Less)),
+            Op::Ne => true,
+            Op::Lt => matches!(min.compare(&self.value), Some(Ordering::Less)),
+            Op::Le => !matches!(min.compare(&self.value), Some(Ordering::Greater)),
+            Op::Gt => matches!(max.compare(&self.value), Some(Ordering::Greater)),
+            Op::Ge => !matches!(max.compare(&self.value), Some(Ordering::Less)),
+        }
+    }
+
+    /// Whether a decoded row satisfies the predicate.
+    pub fn matches_row(&self, row: &Row) -> bool {
+        let field = row
+            .get_column_iter()
+            .find(|(name, _)| name.as_str() == self.column);
+        let Some((_, field)) = field else {
+            return false;
+        };
+        let Some(row_value) = field_to_value(field) else {
+            return false;
+        };
+        let Some(ordering) = row_value.compare(&self.value) else {
+            return false;
+        };
+
+        match self.op {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Ne => ordering != Ordering::Equal,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+fn parse_value(raw: &str) -> Value {
+    if let Ok(b) = raw.parse::<bool>() {
+        return Value::Bool(b);
+    }
+    if let Ok(i) = raw.parse::<i64>() {
+        return Value::Int(i);
+    }
+    if let Ok(f) = raw.parse::<f64>() {
+        return Value::Float(f);
+    }
+    Value::Str(raw.trim_matches('"').to_string())
+}
+
+fn field_to_value(field: &Field) -> Option<Value> {
+    match field {
+        Field::Bool(b) => Some(Value::Bool(*b)),
+        Field::Int(i) => Some(Value::Int(*i as i64)),
+        Field::Long(i) => Some(Value::Int(*i)),
+        Field::Float(f) => Some(Value::Float(f64::from(*f))),
+        Field::Double(f) => Some(Value::Float(*f)),
+        Field::Str(s) => Some(Value::Str(s.clone())),
+        _ => None,
+    }
+}
+
+fn stats_bounds(stats: &Statistics) -> Option<(Value, Value)> {
+    match stats {
+        Statistics::Boolean(s) => Some((Value::Bool(*s.min_opt()?), Value::Bool(*s.max_opt()?))),
+        Statistics::Int32(s) => Some((
+            Value::Int(*s.min_opt()? as i64),
+            Value::Int(*s.max_opt()? as i64),
+        )),
+        Statistics::Int64(s) => Some((Value::Int(*s.min_opt()?), Value::Int(*s.max_opt()?))),
+        Statistics::Float(s) => Some((
+            Value::Float(f64::from(*s.min_opt()?)),
+            Value::Float(f64::from(*s.max_opt()?)),
+        )),
+        Statistics::Double(s) => Some((Value::Float(*s.min_opt()?), Value::Float(*s.max_opt()?))),
+        Statistics::ByteArray(s) => Some((
+            Value::Str(String::from_utf8_lossy(s.min_opt()?.data()).to_string()),
+            Value::Str(String::from_utf8_lossy(s.max_opt()?.data()).to_string()),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_predicates() {
+        let p = Predicate::parse("age >= 30").unwrap();
+        assert_eq!(p.column(), "age");
+        assert_eq!(p.op, Op::Ge);
+        assert_eq!(p.value, Value::Int(30));
+
+        let p = Predicate::parse("name == \"foo\"").unwrap();
+        assert_eq!(p.column(), "name");
+        assert_eq!(p.op, Op::Eq);
+        assert_eq!(p.value, Value::Str("foo".to_string()));
+    }
+
+    #[test]
+    fn prefers_leftmost_operator_over_one_inside_the_value() {
+        let p = Predicate::parse("id > \"x=y\"").unwrap();
+        assert_eq!(p.column(), "id");
+        assert_eq!(p.op, Op::Gt);
+        assert_eq!(p.value, Value::Str("x=y".to_string()));
+    }
+
+    #[test]
+    fn rejects_input_with_no_operator() {
+        assert!(Predicate::parse("just a string").is_err());
+    }
+
+    #[test]
+    fn value_compare_orders_numeric_types_across_variants() {
+        assert_eq!(
+            Value::Int(3).compare(&Value::Float(3.5)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Value::Str("a".to_string()).compare(&Value::Str("b".to_string())),
+            Some(Ordering::Less)
+        );
+        assert_eq!(Value::Bool(true).compare(&Value::Int(1)), None);
+    }
+}
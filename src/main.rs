@@ -1,9 +1,18 @@
+mod bloom;
+mod convert;
+mod count;
+mod filter;
+mod metadata;
+mod source;
+
 use clap::{Arg, Command};
 use parquet::file::reader::{FileReader, SerializedFileReader};
 use parquet::record::Row;
+use parquet::schema::types::{SchemaDescPtr, Type};
 use prettytable::{Cell, Row as PrettyTableRow, Table};
-use std::fs::File;
-use std::io::Result;
+use source::Source;
+use std::io::{Error, ErrorKind, Result};
+use std::sync::Arc;
 
 fn cli() -> Command {
     Command::new("pq-utils")
@@ -24,9 +33,26 @@ fn cli() -> Command {
                     Arg::new("format")
                         .short('f')
                         .long("format")
-                        .help("Output format: csv or json")
-                        .value_parser(["csv", "json"])
+                        .help("Output format: csv, json, or table")
+                        .value_parser(["csv", "json", "table"])
                         .default_value("csv"),
+                )
+                .arg(
+                    Arg::new("columns")
+                        .short('c')
+                        .long("columns")
+                        .help("Comma-separated list of column names to display"),
+                )
+                .arg(
+                    Arg::new("anonymous")
+                        .long("anonymous")
+                        .help("Skip credential resolution for remote s3/http files (not supported for gs)")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("filter")
+                        .long("filter")
+                        .help("Predicate to filter rows, e.g. `col >= 100` or `name == \"foo\"`"),
                 ),
         )
         .subcommand(
@@ -42,8 +68,8 @@ fn cli() -> Command {
                     Arg::new("format")
                         .short('f')
                         .long("format")
-                        .help("Output format: csv or json")
-                        .value_parser(["csv", "json"])
+                        .help("Output format: csv, json, or table")
+                        .value_parser(["csv", "json", "table"])
                         .default_value("csv"),
                 )
                 .arg(
@@ -53,6 +79,23 @@ fn cli() -> Command {
                         .help("Number of rows to display")
                         .value_parser(clap::value_parser!(u64))
                         .default_value("10"),
+                )
+                .arg(
+                    Arg::new("columns")
+                        .short('c')
+                        .long("columns")
+                        .help("Comma-separated list of column names to display"),
+                )
+                .arg(
+                    Arg::new("anonymous")
+                        .long("anonymous")
+                        .help("Skip credential resolution for remote s3/http files (not supported for gs)")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("filter")
+                        .long("filter")
+                        .help("Predicate to filter rows, e.g. `col >= 100` or `name == \"foo\"`"),
                 ),
         )
         .subcommand(
@@ -63,38 +106,387 @@ fn cli() -> Command {
                         .help("The name of the file to display the schema for")
                         .required(true)
                         .index(1),
+                )
+                .arg(
+                    Arg::new("anonymous")
+                        .long("anonymous")
+                        .help("Skip credential resolution for remote s3/http files (not supported for gs)")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("convert")
+                .about("Convert a CSV or JSON file into parquet")
+                .arg(
+                    Arg::new("input")
+                        .help("The CSV or JSON file to convert")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("output")
+                        .help("The parquet file to write")
+                        .required(true)
+                        .index(2),
+                )
+                .arg(
+                    Arg::new("format")
+                        .short('f')
+                        .long("format")
+                        .help("Input format: csv or json")
+                        .value_parser(["csv", "json"])
+                        .default_value("csv"),
+                )
+                .arg(
+                    Arg::new("compression")
+                        .long("compression")
+                        .help("Compression codec for the output file")
+                        .value_parser(["uncompressed", "snappy", "gzip", "zstd", "brotli", "lz4"])
+                        .default_value("snappy"),
+                )
+                .arg(
+                    Arg::new("encoding")
+                        .long("encoding")
+                        .help("Column encoding for the output file")
+                        .value_parser([
+                            "plain",
+                            "dictionary",
+                            "rle",
+                            "delta_binary_packed",
+                            "delta_byte_array",
+                        ]),
+                )
+                .arg(
+                    Arg::new("row_group_size")
+                        .long("row-group-size")
+                        .help("Maximum number of rows per row group")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("statistics")
+                        .long("statistics")
+                        .help("Statistics level to write: none, chunk, or page")
+                        .value_parser(["none", "chunk", "page"])
+                        .default_value("page"),
+                )
+                .arg(
+                    Arg::new("schema")
+                        .short('s')
+                        .long("schema")
+                        .help("Path to an Arrow schema JSON file (otherwise inferred)"),
+                ),
+        )
+        .subcommand(
+            Command::new("metadata")
+                .visible_alias("layout")
+                .about("Display row-group and column-chunk physical layout details")
+                .arg(
+                    Arg::new("file")
+                        .help("The name of the file to inspect")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("format")
+                        .short('f')
+                        .long("format")
+                        .help("Output format: table or json")
+                        .value_parser(["table", "json"])
+                        .default_value("table"),
+                )
+                .arg(
+                    Arg::new("anonymous")
+                        .long("anonymous")
+                        .help("Skip credential resolution for remote s3/http files (not supported for gs)")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("bloom")
+                .about("Check whether a value may be present in a column's bloom filter")
+                .arg(
+                    Arg::new("file")
+                        .help("The name of the file to probe")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("column")
+                        .short('c')
+                        .long("column")
+                        .help("Column to probe")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("value")
+                        .short('v')
+                        .long("value")
+                        .help("Value to look up")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("anonymous")
+                        .long("anonymous")
+                        .help("Skip credential resolution for remote s3/http files (not supported for gs)")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("count")
+                .about("Print the total number of rows in a file")
+                .arg(
+                    Arg::new("file")
+                        .help("The name of the file to count")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("row_groups")
+                        .long("row-groups")
+                        .help("Also print a per-row-group breakdown")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("anonymous")
+                        .long("anonymous")
+                        .help("Skip credential resolution for remote s3/http files (not supported for gs)")
+                        .action(clap::ArgAction::SetTrue),
                 ),
         )
 }
 
-fn display_parquet_data(file: &str, format: &str, num_records: Option<u64>) -> Result<()> {
-    let file = File::open(file)?;
+fn display_parquet_data(
+    file: &str,
+    format: &str,
+    num_records: Option<u64>,
+    columns: Option<&str>,
+    anonymous: bool,
+    filter_expr: Option<&str>,
+) -> Result<()> {
+    let source = Source::open(file, anonymous)?;
     match format {
-        "csv" => display_parquet_data_csv(file, num_records),
-        "json" => display_parquet_data_json(file, num_records),
+        "csv" => display_parquet_data_csv(source, num_records, columns, filter_expr),
+        "json" => display_parquet_data_json(source, num_records, columns, filter_expr),
+        "table" => display_parquet_data_table(source, num_records, columns, filter_expr),
         _ => unreachable!("Handled by value_parser"),
     }
 }
 
-fn display_parquet_data_csv(file: File, num_records: Option<u64>) -> Result<()> {
-    let reader = SerializedFileReader::new(file)?;
-    let iter = reader.get_row_iter(None)?;
-    let iter = iter.take(num_records.unwrap_or(u64::MAX) as usize);
+/// Number of rows buffered per pretty-printed table, so `cat --format
+/// table` (which has no `-n`) doesn't have to hold the whole file in
+/// memory to align columns.
+const TABLE_CHUNK_SIZE: usize = 1000;
 
-    let mut writer = csv::Writer::from_writer(std::io::stdout());
+fn display_parquet_data_table(
+    source: Source,
+    num_records: Option<u64>,
+    columns: Option<&str>,
+    filter_expr: Option<&str>,
+) -> Result<()> {
+    let reader = SerializedFileReader::new(source)?;
+    let schema_descr = reader.metadata().file_metadata().schema_descr_ptr();
+    let predicate = filter_expr.map(filter::Predicate::parse).transpose()?;
+    let projection = resolve_projection(&schema_descr, columns, predicate.as_ref())?;
+    let num_display_cols = projection.display_names.len();
 
-    let schema_descr = reader.metadata().file_metadata().schema_descr();
-    let headers: Vec<String> = schema_descr
-        .columns()
-        .iter()
-        .map(|col| col.name().to_string())
+    let kept_groups: Vec<usize> = (0..reader.num_row_groups())
+        .filter(|&i| match &predicate {
+            Some(p) => p.row_group_may_match(reader.metadata().row_group(i)),
+            None => true,
+        })
         .collect();
-    writer.write_record(&headers)?;
 
-    for record in iter {
-        let row: Row = record?;
+    let mut remaining = num_records.unwrap_or(u64::MAX) as usize;
+    let mut chunk = Vec::with_capacity(TABLE_CHUNK_SIZE.min(remaining));
+
+    'outer: for i in kept_groups {
+        if remaining == 0 {
+            break 'outer;
+        }
+        let row_group_reader = reader.get_row_group(i)?;
+        let iter = row_group_reader.get_row_iter(projection.schema.clone())?;
+        for record in iter {
+            let row: Row = record?;
+            if let Some(p) = &predicate {
+                if !p.matches_row(&row) {
+                    continue;
+                }
+            }
+            chunk.push(row);
+            remaining -= 1;
+
+            if chunk.len() >= TABLE_CHUNK_SIZE {
+                print_table_chunk(&projection.display_names, &chunk, num_display_cols);
+                chunk.clear();
+            }
+            if remaining == 0 {
+                break 'outer;
+            }
+        }
+    }
+    if !chunk.is_empty() {
+        print_table_chunk(&projection.display_names, &chunk, num_display_cols);
+    }
+    Ok(())
+}
+
+fn print_table_chunk(headers: &[String], rows: &[Row], num_display_cols: usize) {
+    let mut table = Table::new();
+    table.add_row(PrettyTableRow::new(
+        headers.iter().map(|h| Cell::new(h)).collect(),
+    ));
+    for row in rows {
+        let cells: Vec<Cell> = row
+            .get_column_iter()
+            .take(num_display_cols)
+            .map(|field| match field.1 {
+                parquet::record::Field::Str(s) => Cell::new(s),
+                other => Cell::new(&other.to_string()),
+            })
+            .collect();
+        table.add_row(PrettyTableRow::new(cells));
+    }
+    table.printstd();
+}
+
+/// Scan only the row groups that `predicate` cannot rule out via column
+/// statistics, decoding at most `limit` matching rows.
+fn read_rows(
+    reader: &SerializedFileReader<Source>,
+    projection: Option<Type>,
+    predicate: Option<&filter::Predicate>,
+    limit: usize,
+) -> Result<Vec<Row>> {
+    let kept_groups: Vec<usize> = (0..reader.num_row_groups())
+        .filter(|&i| match predicate {
+            Some(p) => p.row_group_may_match(reader.metadata().row_group(i)),
+            None => true,
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    if limit == 0 {
+        return Ok(rows);
+    }
+    'outer: for i in kept_groups {
+        let row_group_reader = reader.get_row_group(i)?;
+        let iter = row_group_reader.get_row_iter(projection.clone())?;
+        for record in iter {
+            let row: Row = record?;
+            if let Some(p) = predicate {
+                if !p.matches_row(&row) {
+                    continue;
+                }
+            }
+            rows.push(row);
+            if rows.len() >= limit {
+                break 'outer;
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// Build a projected schema containing only `names`, in the order given, by
+/// looking each one up in the file's `SchemaDescriptor` and assembling a new
+/// group `Type` from the matching leaf columns.
+fn build_projection(schema_descr: &SchemaDescPtr, names: &[String]) -> Result<Type> {
+    let fields = names
+        .iter()
+        .map(|name| {
+            schema_descr
+                .columns()
+                .iter()
+                .find(|col| col.name() == name)
+                .map(|col| col.self_type_ptr())
+                .ok_or_else(|| {
+                    Error::new(ErrorKind::InvalidInput, format!("unknown column: {}", name))
+                })
+        })
+        .collect::<Result<Vec<Arc<Type>>>>()?;
+
+    Type::group_type_builder(schema_descr.name())
+        .with_fields(fields)
+        .build()
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, e.to_string()))
+}
+
+/// The schema projection to decode and the subset of its columns that should
+/// actually be displayed. When `--filter` names a column outside of
+/// `--columns`, it's added to the projection so the predicate can still be
+/// evaluated, but kept out of `display_names` so it isn't shown in the
+/// output.
+struct Projection {
+    schema: Option<Type>,
+    display_names: Vec<String>,
+}
+
+fn resolve_projection(
+    schema_descr: &SchemaDescPtr,
+    columns: Option<&str>,
+    predicate: Option<&filter::Predicate>,
+) -> Result<Projection> {
+    if let Some(p) = predicate {
+        if !schema_descr
+            .columns()
+            .iter()
+            .any(|col| col.name() == p.column())
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unknown column: {}", p.column()),
+            ));
+        }
+    }
+
+    let Some(columns) = columns else {
+        let display_names = schema_descr
+            .columns()
+            .iter()
+            .map(|col| col.name().to_string())
+            .collect();
+        return Ok(Projection {
+            schema: None,
+            display_names,
+        });
+    };
+
+    let display_names: Vec<String> = columns.split(',').map(|s| s.trim().to_string()).collect();
+    let mut names = display_names.clone();
+    if let Some(p) = predicate {
+        if !names.iter().any(|n| n == p.column()) {
+            names.push(p.column().to_string());
+        }
+    }
+
+    Ok(Projection {
+        schema: Some(build_projection(schema_descr, &names)?),
+        display_names,
+    })
+}
+
+fn display_parquet_data_csv(
+    source: Source,
+    num_records: Option<u64>,
+    columns: Option<&str>,
+    filter_expr: Option<&str>,
+) -> Result<()> {
+    let reader = SerializedFileReader::new(source)?;
+    let schema_descr = reader.metadata().file_metadata().schema_descr_ptr();
+    let predicate = filter_expr.map(filter::Predicate::parse).transpose()?;
+    let projection = resolve_projection(&schema_descr, columns, predicate.as_ref())?;
+    let num_display_cols = projection.display_names.len();
+
+    let limit = num_records.unwrap_or(u64::MAX) as usize;
+    let rows = read_rows(&reader, projection.schema, predicate.as_ref(), limit)?;
+
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record(&projection.display_names)?;
+
+    for row in rows {
         let values: Vec<String> = row
             .get_column_iter()
+            .take(num_display_cols)
             .map(|field| match field.1 {
                 parquet::record::Field::Str(s) => s.clone(),
                 _ => field.1.to_string(),
@@ -107,19 +499,28 @@ fn display_parquet_data_csv(file: File, num_records: Option<u64>) -> Result<()>
     Ok(())
 }
 
-fn display_parquet_data_json(file: File, num_records: Option<u64>) -> Result<()> {
-    let reader = SerializedFileReader::new(file)?;
-    let iter = reader.get_row_iter(None)?;
-    let iter = iter.take(num_records.unwrap_or(u64::MAX) as usize);
+fn display_parquet_data_json(
+    source: Source,
+    num_records: Option<u64>,
+    columns: Option<&str>,
+    filter_expr: Option<&str>,
+) -> Result<()> {
+    let reader = SerializedFileReader::new(source)?;
+    let schema_descr = reader.metadata().file_metadata().schema_descr_ptr();
+    let predicate = filter_expr.map(filter::Predicate::parse).transpose()?;
+    let projection = resolve_projection(&schema_descr, columns, predicate.as_ref())?;
+    let col_names = &projection.display_names;
+
+    let limit = num_records.unwrap_or(u64::MAX) as usize;
+    let matched_rows = read_rows(&reader, projection.schema, predicate.as_ref(), limit)?;
 
     let mut rows = Vec::new();
 
-    for record in iter {
-        let row: Row = record?;
+    for row in matched_rows {
         let mut obj = serde_json::Map::new();
 
-        for (i, field) in row.get_column_iter().enumerate() {
-            let col_name = reader.metadata().file_metadata().schema_descr().columns()[i].name();
+        for (i, field) in row.get_column_iter().take(col_names.len()).enumerate() {
+            let col_name = &col_names[i];
             obj.insert(
                 col_name.to_string(),
                 match field.1 {
@@ -144,9 +545,9 @@ fn display_parquet_data_json(file: File, num_records: Option<u64>) -> Result<()>
     Ok(())
 }
 
-fn display_parquet_schema(file: &str) -> Result<()> {
-    let file = File::open(file)?;
-    let reader = SerializedFileReader::new(file)?;
+fn display_parquet_schema(file: &str, anonymous: bool) -> Result<()> {
+    let source = Source::open(file, anonymous)?;
+    let reader = SerializedFileReader::new(source)?;
     let schema_descr = reader.metadata().file_metadata().schema_descr();
 
     let mut table = Table::new();
@@ -178,7 +579,12 @@ fn main() {
         Some(("cat", subcommand)) => {
             let file = subcommand.get_one::<String>("file").unwrap();
             let format = subcommand.get_one::<String>("format").unwrap();
-            if let Err(e) = display_parquet_data(file, format, None) {
+            let columns = subcommand.get_one::<String>("columns").map(|s| s.as_str());
+            let anonymous = subcommand.get_flag("anonymous");
+            let filter_expr = subcommand.get_one::<String>("filter").map(|s| s.as_str());
+            if let Err(e) =
+                display_parquet_data(file, format, None, columns, anonymous, filter_expr)
+            {
                 eprintln!("Error displaying file: {}", e);
             }
         }
@@ -186,16 +592,67 @@ fn main() {
             let file = subcommand.get_one::<String>("file").unwrap();
             let format = subcommand.get_one::<String>("format").unwrap();
             let num_records = subcommand.get_one::<u64>("n_rows").copied();
-            if let Err(e) = display_parquet_data(file, format, num_records) {
+            let columns = subcommand.get_one::<String>("columns").map(|s| s.as_str());
+            let anonymous = subcommand.get_flag("anonymous");
+            let filter_expr = subcommand.get_one::<String>("filter").map(|s| s.as_str());
+            if let Err(e) =
+                display_parquet_data(file, format, num_records, columns, anonymous, filter_expr)
+            {
                 eprintln!("Error displaying file: {}", e);
             }
         }
         Some(("schema", subcommand)) => {
             let file = subcommand.get_one::<String>("file").unwrap();
-            if let Err(e) = display_parquet_schema(file) {
+            let anonymous = subcommand.get_flag("anonymous");
+            if let Err(e) = display_parquet_schema(file, anonymous) {
                 eprintln!("Error displaying schema: {}", e);
             }
         }
+        Some(("convert", subcommand)) => {
+            let input = subcommand.get_one::<String>("input").unwrap();
+            let output = subcommand.get_one::<String>("output").unwrap();
+            let options = convert::ConvertOptions {
+                format: subcommand.get_one::<String>("format").unwrap(),
+                compression: subcommand.get_one::<String>("compression").unwrap(),
+                encoding: subcommand.get_one::<String>("encoding").map(|s| s.as_str()),
+                row_group_size: subcommand.get_one::<usize>("row_group_size").copied(),
+                statistics: subcommand.get_one::<String>("statistics").unwrap(),
+                schema_path: subcommand.get_one::<String>("schema").map(|s| s.as_str()),
+            };
+            if let Err(e) = convert::convert(input, output, options) {
+                eprintln!("Error converting file: {}", e);
+            }
+        }
+        Some(("metadata", subcommand)) => {
+            let file = subcommand.get_one::<String>("file").unwrap();
+            let format = subcommand.get_one::<String>("format").unwrap();
+            let anonymous = subcommand.get_flag("anonymous");
+            if let Err(e) = metadata::display(file, anonymous, format) {
+                eprintln!("Error displaying metadata: {}", e);
+            }
+        }
+        Some(("bloom", subcommand)) => {
+            let file = subcommand.get_one::<String>("file").unwrap();
+            let column = subcommand.get_one::<String>("column").unwrap();
+            let value = subcommand.get_one::<String>("value").unwrap();
+            let anonymous = subcommand.get_flag("anonymous");
+            match bloom::probe(file, anonymous, column, value) {
+                Ok(true) => {}
+                Ok(false) => std::process::exit(1),
+                Err(e) => {
+                    eprintln!("Error probing bloom filter: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        Some(("count", subcommand)) => {
+            let file = subcommand.get_one::<String>("file").unwrap();
+            let row_groups = subcommand.get_flag("row_groups");
+            let anonymous = subcommand.get_flag("anonymous");
+            if let Err(e) = count::display(file, anonymous, row_groups) {
+                eprintln!("Error counting rows: {}", e);
+            }
+        }
         _ => unreachable!("Handled by clap"),
     }
 }
@@ -0,0 +1,153 @@
+use crate::source::Source;
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::statistics::Statistics;
+use prettytable::{Cell, Row as PrettyTableRow, Table};
+use serde_json::json;
+use std::io::Result;
+
+pub fn display(file: &str, anonymous: bool, format: &str) -> Result<()> {
+    let source = Source::open(file, anonymous)?;
+    let reader = SerializedFileReader::new(source)?;
+    let row_groups = reader.metadata().row_groups();
+
+    match format {
+        "json" => display_json(row_groups),
+        _ => display_table(row_groups),
+    }
+}
+
+fn display_table(row_groups: &[RowGroupMetaData]) -> Result<()> {
+    for (i, rg) in row_groups.iter().enumerate() {
+        println!("Row group {} ({} rows)", i, rg.num_rows());
+
+        let mut table = Table::new();
+        table.add_row(PrettyTableRow::new(vec![
+            Cell::new("Column"),
+            Cell::new("Compressed size"),
+            Cell::new("Uncompressed size"),
+            Cell::new("Codec"),
+            Cell::new("Encodings"),
+            Cell::new("Min"),
+            Cell::new("Max"),
+            Cell::new("Nulls"),
+        ]));
+
+        for col in rg.columns() {
+            let (min, max, nulls) = stats_strings(col.statistics());
+            let encodings: Vec<String> = col
+                .encodings()
+                .iter()
+                .map(|e| format!("{:?}", e))
+                .collect();
+            table.add_row(PrettyTableRow::new(vec![
+                Cell::new(col.column_path().string().as_str()),
+                Cell::new(&col.compressed_size().to_string()),
+                Cell::new(&col.uncompressed_size().to_string()),
+                Cell::new(&format!("{:?}", col.compression())),
+                Cell::new(&encodings.join(", ")),
+                Cell::new(&min),
+                Cell::new(&max),
+                Cell::new(&nulls),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+    }
+    Ok(())
+}
+
+fn display_json(row_groups: &[RowGroupMetaData]) -> Result<()> {
+    let groups: Vec<_> = row_groups
+        .iter()
+        .enumerate()
+        .map(|(i, rg)| {
+            let columns: Vec<_> = rg
+                .columns()
+                .iter()
+                .map(|col| {
+                    let (min, max, nulls) = stats_strings(col.statistics());
+                    json!({
+                        "column": col.column_path().string(),
+                        "compressed_size": col.compressed_size(),
+                        "uncompressed_size": col.uncompressed_size(),
+                        "codec": format!("{:?}", col.compression()),
+                        "encodings": col
+                            .encodings()
+                            .iter()
+                            .map(|e| format!("{:?}", e))
+                            .collect::<Vec<_>>(),
+                        "min": min,
+                        "max": max,
+                        "null_count": nulls,
+                    })
+                })
+                .collect();
+            json!({
+                "row_group": i,
+                "num_rows": rg.num_rows(),
+                "columns": columns,
+            })
+        })
+        .collect();
+
+    serde_json::to_writer(std::io::stdout(), &groups)?;
+    Ok(())
+}
+
+fn stats_strings(statistics: Option<&Statistics>) -> (String, String, String) {
+    match statistics {
+        Some(stats) => (
+            stats_min(stats),
+            stats_max(stats),
+            stats
+                .null_count_opt()
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+        ),
+        None => (String::new(), String::new(), String::new()),
+    }
+}
+
+fn stats_min(stats: &Statistics) -> String {
+    match stats {
+        Statistics::Boolean(s) => opt_to_string(s.min_opt()),
+        Statistics::Int32(s) => opt_to_string(s.min_opt()),
+        Statistics::Int64(s) => opt_to_string(s.min_opt()),
+        Statistics::Int96(s) => opt_to_string(s.min_opt()),
+        Statistics::Float(s) => opt_to_string(s.min_opt()),
+        Statistics::Double(s) => opt_to_string(s.min_opt()),
+        Statistics::ByteArray(s) => s
+            .min_opt()
+            .map(|v| String::from_utf8_lossy(v.data()).to_string())
+            .unwrap_or_default(),
+        Statistics::FixedLenByteArray(s) => s
+            .min_opt()
+            .map(|v| String::from_utf8_lossy(v.data()).to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn stats_max(stats: &Statistics) -> String {
+    match stats {
+        Statistics::Boolean(s) => opt_to_string(s.max_opt()),
+        Statistics::Int32(s) => opt_to_string(s.max_opt()),
+        Statistics::Int64(s) => opt_to_string(s.max_opt()),
+        Statistics::Int96(s) => opt_to_string(s.max_opt()),
+        Statistics::Float(s) => opt_to_string(s.max_opt()),
+        Statistics::Double(s) => opt_to_string(s.max_opt()),
+        Statistics::ByteArray(s) => s
+            .max_opt()
+            .map(|v| String::from_utf8_lossy(v.data()).to_string())
+            .unwrap_or_default(),
+        Statistics::FixedLenByteArray(s) => s
+            .max_opt()
+            .map(|v| String::from_utf8_lossy(v.data()).to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn opt_to_string<T: std::fmt::Display>(value: Option<&T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
@@ -0,0 +1,87 @@
+use bytes::Bytes;
+use object_store::parse_url_opts;
+use parquet::errors::Result as ParquetResult;
+use parquet::file::reader::{ChunkReader, Length};
+use std::fs::File;
+use std::io::{Error, ErrorKind, Read, Result};
+use url::Url;
+
+/// A local file or an in-memory buffer fetched from a remote object store.
+pub enum Source {
+    Local(File),
+    Remote(Bytes),
+}
+
+impl Source {
+    /// Resolve `file` to a [`Source`], fetching it remotely if it's a
+    /// `s3`/`gs`/`http`/`https` URL and opening it as a local path otherwise.
+    pub fn open(file: &str, anonymous: bool) -> Result<Source> {
+        match parse_remote_url(file) {
+            Some(url) => Ok(Source::Remote(fetch_remote(&url, anonymous)?)),
+            None => Ok(Source::Local(File::open(file)?)),
+        }
+    }
+}
+
+fn parse_remote_url(file: &str) -> Option<Url> {
+    Url::parse(file)
+        .ok()
+        .filter(|url| matches!(url.scheme(), "s3" | "gs" | "http" | "https"))
+}
+
+fn fetch_remote(url: &Url, anonymous: bool) -> Result<Bytes> {
+    if anonymous && url.scheme() == "gs" {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            "--anonymous isn't supported for gs:// URLs: object_store has no anonymous-access \
+             mechanism for GCS, unlike its `skip_signature` option for S3",
+        ));
+    }
+    let options: Vec<(&str, &str)> = if anonymous {
+        vec![("skip_signature", "true")]
+    } else {
+        vec![]
+    };
+    let (store, path) =
+        parse_url_opts(url, options).map_err(|e| Error::other(e.to_string()))?;
+
+    let runtime =
+        tokio::runtime::Runtime::new().map_err(|e| Error::other(e.to_string()))?;
+    runtime.block_on(async {
+        let result = store
+            .get(&path)
+            .await
+            .map_err(|e| Error::other(e.to_string()))?;
+        result
+            .bytes()
+            .await
+            .map_err(|e| Error::other(e.to_string()))
+    })
+}
+
+impl Length for Source {
+    fn len(&self) -> u64 {
+        match self {
+            Source::Local(f) => Length::len(f),
+            Source::Remote(b) => b.len() as u64,
+        }
+    }
+}
+
+impl ChunkReader for Source {
+    type T = Box<dyn Read + Send>;
+
+    fn get_read(&self, start: u64) -> ParquetResult<Self::T> {
+        match self {
+            Source::Local(f) => Ok(Box::new(f.get_read(start)?)),
+            Source::Remote(b) => Ok(Box::new(b.get_read(start)?)),
+        }
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> ParquetResult<Bytes> {
+        match self {
+            Source::Local(f) => f.get_bytes(start, length),
+            Source::Remote(b) => b.get_bytes(start, length),
+        }
+    }
+}